@@ -94,6 +94,8 @@ export enum IpcChannel {
   Mcp_GetServerVersion = 'mcp:get-server-version',
   Mcp_Progress = 'mcp:progress',
   Mcp_GetServerLogs = 'mcp:get-server-logs',
+  Mcp_SetLogCapture = 'mcp:set-log-capture',
+  Mcp_ClearServerLogs = 'mcp:clear-server-logs',
   Mcp_ServerLog = 'mcp:server-log',
   // Python
   Python_Execute = 'python:execute',
@@ -191,6 +193,7 @@ export enum IpcChannel {
   File_WriteWithId = 'file:writeWithId',
   File_SaveImage = 'file:saveImage',
   File_Base64Image = 'file:base64Image',
+  File_Thumbnail = 'file:thumbnail',
   File_SaveBase64Image = 'file:saveBase64Image',
   File_SavePastedImage = 'file:savePastedImage',
   File_Download = 'file:download',
@@ -200,13 +203,20 @@ export enum IpcChannel {
   File_GetPdfInfo = 'file:getPdfInfo',
   Fs_Read = 'fs:read',
   Fs_ReadText = 'fs:readText',
+  Fs_ListAllowedDirs = 'fs:listAllowedDirs',
+  Fs_SetAllowedDirs = 'fs:setAllowedDirs',
+  Fs_RevokeAllowedDir = 'fs:revokeAllowedDir',
   File_OpenWithRelativePath = 'file:openWithRelativePath',
   File_IsTextFile = 'file:isTextFile',
   File_IsDirectory = 'file:isDirectory',
   File_ListDirectory = 'file:listDirectory',
+  File_IndexDirectory = 'file:indexDirectory',
+  File_CancelIndex = 'file:cancelIndex',
   File_GetDirectoryStructure = 'file:getDirectoryStructure',
   File_CheckFileName = 'file:checkFileName',
   File_ValidateNotesDirectory = 'file:validateNotesDirectory',
+  Notes_Create = 'notes:create',
+  Notes_Move = 'notes:move',
   File_StartWatcher = 'file:startWatcher',
   File_StopWatcher = 'file:stopWatcher',
   File_PauseWatcher = 'file:pauseWatcher',
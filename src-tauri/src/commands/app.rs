@@ -130,12 +130,23 @@ pub fn app_quit(app: &AppHandle) -> Result<()> {
 }
 
 pub fn open_website(_app: &AppHandle, url: String) -> Result<()> {
+    let url = commands::file::validate_website_url(&url)?;
     open(None, url, None).map_err(|e| DromeError::Message(e.to_string()))?;
     Ok(())
 }
 
-pub fn open_path(_app: &AppHandle, path: String) -> Result<()> {
-    open(None, path, None).map_err(|e| DromeError::Message(e.to_string()))?;
+pub fn open_path(app: &AppHandle, state: &State<'_, AppState>, path: String) -> Result<()> {
+    // Besides the allowed dirs, settings pages open the log dir and the MCP
+    // binaries dir.
+    let mut roots = commands::file::allowed_roots(state);
+    if let Ok(logs_dir) = app.path().app_log_dir() {
+        roots.push(logs_dir);
+    }
+    if let Ok(info) = commands::mcp::mcp_get_install_info() {
+        roots.push(PathBuf::from(info.dir));
+    }
+    let target = commands::file::validate_open_target(&roots, &path)?;
+    open(None, target, None).map_err(|e| DromeError::Message(e.to_string()))?;
     Ok(())
 }
 
@@ -5,6 +5,7 @@ use mime_guess::MimeGuess;
 use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
@@ -145,22 +146,19 @@ fn ensure_dir(path: &Path) -> Result<()> {
     Ok(())
 }
 
-fn is_allowed(state: &State<'_, AppState>, path: &Path) -> bool {
-    if path.starts_with(&state.app_data_dir) {
-        return true;
-    }
-    if path.starts_with(&state.app_config_dir) {
-        return true;
-    }
-    let dirs = state.allowed_dirs.lock().ok();
-    if let Some(dirs) = dirs {
-        for allowed in dirs.iter() {
-            if path.starts_with(allowed) {
-                return true;
-            }
-        }
+/// The app data/config dirs plus every directory granted at runtime.
+pub(crate) fn allowed_roots(state: &State<'_, AppState>) -> Vec<PathBuf> {
+    let mut roots = vec![state.app_data_dir.clone(), state.app_config_dir.clone()];
+    if let Ok(dirs) = state.allowed_dirs.lock() {
+        roots.extend(dirs.iter().cloned());
     }
-    false
+    roots
+}
+
+fn is_allowed(state: &State<'_, AppState>, path: &Path) -> bool {
+    allowed_roots(state)
+        .iter()
+        .any(|root| path.starts_with(root))
 }
 
 fn allow_dir(state: &State<'_, AppState>, dir: &Path) {
@@ -462,9 +460,145 @@ pub fn file_open(
     }))
 }
 
-pub fn file_open_path(_app: &AppHandle, path: String) -> Result<()> {
-    let path = strip_file_scheme(&path).to_string();
-    open(None, path, None).map_err(|e| DromeError::Message(e.to_string()))?;
+/// Extensions the OS opener runs instead of showing. On Windows this list is
+/// the only guard, since there is no exec bit to check.
+const LAUNCHABLE_EXTS: &[&str] = &[
+    // Windows
+    "application",
+    "appref-ms",
+    "bat",
+    "cmd",
+    "com",
+    "cpl",
+    "exe",
+    "hta",
+    "jar",
+    "js",
+    "jse",
+    "lnk",
+    "msc",
+    "msi",
+    "pif",
+    "ps1",
+    "psm1",
+    "py",
+    "pyw",
+    "reg",
+    "scf",
+    "scr",
+    "url",
+    "vbe",
+    "vbs",
+    "ws",
+    "wsf",
+    // macOS
+    "app",
+    "command",
+    "mpkg",
+    "pkg",
+    "scpt",
+    "terminal",
+    "tool",
+    "webloc",
+    "workflow",
+    // Linux
+    "appimage",
+    "desktop",
+    "run",
+    "sh",
+];
+
+/// Returns the URL scheme of `target`, ignoring Windows drive letters (`C:\`).
+pub(crate) fn url_scheme(target: &str) -> Option<&str> {
+    let (scheme, _) = target.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = scheme.len() > 1
+        && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some(scheme)
+}
+
+/// Like `is_allowed`, but for a canonicalized `path`, so roots are matched both
+/// as configured and resolved (e.g. `/var` vs `/private/var` on macOS).
+fn is_allowed_resolved(roots: &[PathBuf], path: &Path) -> bool {
+    roots.iter().any(|root| {
+        path.starts_with(root) || fs::canonicalize(root).is_ok_and(|root| path.starts_with(root))
+    })
+}
+
+/// Whether the OS opener would launch `path` rather than show it. Checked for
+/// directories too, since macOS `.app` bundles are directories.
+fn is_launchable(path: &Path) -> bool {
+    if LAUNCHABLE_EXTS.contains(&ext_lower(path).trim_start_matches('.')) {
+        return true;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if path.is_file()
+            && fs::metadata(path).is_ok_and(|meta| meta.permissions().mode() & 0o111 != 0)
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// Resolves a local path (or `file://` URL) and checks it lies under `roots`.
+fn resolve_open_path(roots: &[PathBuf], target: &str) -> Result<PathBuf> {
+    let path = fs::canonicalize(normalize_path(target))
+        .map_err(|_| DromeError::Message(format!("Path does not exist: {target}")))?;
+    if !is_allowed_resolved(roots, &path) {
+        return Err(DromeError::Message("Path not allowed".into()));
+    }
+    Ok(path)
+}
+
+fn refuse_launchable(path: &Path) -> Result<()> {
+    if is_launchable(path) {
+        return Err(DromeError::Message(format!(
+            "Refusing to launch executable: {}",
+            path_to_string(path)
+        )));
+    }
+    Ok(())
+}
+
+/// Validates a renderer-supplied target before handing it to the OS opener.
+/// Only `http(s)` URLs and existing, non-launchable paths under `roots` are
+/// accepted.
+pub(crate) fn validate_open_target(roots: &[PathBuf], target: &str) -> Result<String> {
+    let target = target.trim();
+    match url_scheme(target).map(|s| s.to_ascii_lowercase()) {
+        Some(scheme) if scheme == "http" || scheme == "https" => return Ok(target.to_string()),
+        Some(scheme) if scheme != "file" => {
+            return Err(DromeError::Message(format!(
+                "Refusing to open URL with scheme '{scheme}'"
+            )))
+        }
+        _ => {}
+    }
+
+    let path = resolve_open_path(roots, target)?;
+    refuse_launchable(&path)?;
+    Ok(path_to_string(&path))
+}
+
+/// Validates a URL for `open:website`: `http(s)`, plus `mailto` for the
+/// feedback link.
+pub(crate) fn validate_website_url(url: &str) -> Result<String> {
+    let url = url.trim();
+    match url_scheme(url).map(|s| s.to_ascii_lowercase()) {
+        Some(scheme) if matches!(scheme.as_str(), "http" | "https" | "mailto") => {
+            Ok(url.to_string())
+        }
+        _ => Err(DromeError::Message(format!("Refusing to open URL: {url}"))),
+    }
+}
+
+pub fn file_open_path(_app: &AppHandle, state: &State<'_, AppState>, path: String) -> Result<()> {
+    let target = validate_open_target(&allowed_roots(state), &path)?;
+    open(None, target, None).map_err(|e| DromeError::Message(e.to_string()))?;
     Ok(())
 }
 
@@ -862,10 +996,11 @@ pub fn file_upload(
 }
 
 pub fn file_delete(state: &State<'_, AppState>, file_id: String) -> Result<()> {
-    let path = files_dir(state).join(file_id);
+    let path = files_dir(state).join(&file_id);
     if path.exists() && path.is_file() {
         fs::remove_file(path)?;
     }
+    remove_cached_thumbnails(&thumbnail_cache_dir(state), &format!("{file_id}_"));
     Ok(())
 }
 
@@ -1130,7 +1265,7 @@ pub fn file_is_directory(state: &State<'_, AppState>, file_path: String) -> Resu
     Ok(path.is_dir())
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct DirectoryListOptions {
     recursive: Option<bool>,
@@ -1140,21 +1275,19 @@ struct DirectoryListOptions {
     include_directories: Option<bool>,
     max_entries: Option<usize>,
     search_pattern: Option<String>,
+    /// Only used by `file_index_directory`.
+    batch_size: Option<usize>,
 }
 
 impl DirectoryListOptions {
-    fn merged(options: Option<Value>) -> Self {
-        let parsed = options
+    fn parsed(options: Option<Value>) -> Self {
+        options
             .and_then(|v| serde_json::from_value::<DirectoryListOptions>(v).ok())
-            .unwrap_or(DirectoryListOptions {
-                recursive: None,
-                max_depth: None,
-                include_hidden: None,
-                include_files: None,
-                include_directories: None,
-                max_entries: None,
-                search_pattern: None,
-            });
+            .unwrap_or_default()
+    }
+
+    fn merged(options: Option<Value>) -> Self {
+        let parsed = Self::parsed(options);
 
         DirectoryListOptions {
             recursive: Some(parsed.recursive.unwrap_or(true)),
@@ -1164,6 +1297,7 @@ impl DirectoryListOptions {
             include_directories: Some(parsed.include_directories.unwrap_or(true)),
             max_entries: Some(parsed.max_entries.unwrap_or(20)),
             search_pattern: Some(parsed.search_pattern.unwrap_or_else(|| ".".to_string())),
+            batch_size: parsed.batch_size,
         }
     }
 }
@@ -1232,6 +1366,169 @@ pub fn file_list_directory(
     Ok(out)
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DirectoryIndexEntry {
+    path: String,
+    name: String,
+    is_directory: bool,
+    depth: usize,
+}
+
+static INDEX_JOBS: OnceLock<Mutex<HashMap<String, Arc<AtomicBool>>>> = OnceLock::new();
+
+fn index_jobs() -> &'static Mutex<HashMap<String, Arc<AtomicBool>>> {
+    INDEX_JOBS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Registers a new index job and returns its token and cancellation flag.
+fn register_index_job() -> Result<(String, Arc<AtomicBool>)> {
+    let token = Uuid::new_v4().to_string();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    index_jobs()
+        .lock()
+        .map_err(|_| DromeError::Message("Index job registry is unavailable".into()))?
+        .insert(token.clone(), cancelled.clone());
+    Ok((token, cancelled))
+}
+
+fn finish_index_job(token: &str) {
+    if let Ok(mut jobs) = index_jobs().lock() {
+        jobs.remove(token);
+    }
+}
+
+/// Walks `root`, handing entries to `emit` in batches until the walk ends or
+/// `cancelled` is set. Returns the number of entries collected and whether the
+/// walk was cancelled; a cancelled walk drops its last partial batch.
+fn walk_directory_index(
+    root: &Path,
+    options: &DirectoryListOptions,
+    cancelled: &AtomicBool,
+    mut emit: impl FnMut(Vec<DirectoryIndexEntry>),
+) -> (usize, bool) {
+    let recursive = options.recursive.unwrap_or(true);
+    let max_depth = options.max_depth.unwrap_or(10);
+    let include_hidden = options.include_hidden.unwrap_or(false);
+    let include_files = options.include_files.unwrap_or(true);
+    let include_directories = options.include_directories.unwrap_or(true);
+    let max_entries = options.max_entries.unwrap_or(usize::MAX);
+    let batch_size = options.batch_size.unwrap_or(200).max(1);
+    let search_pattern = options
+        .search_pattern
+        .as_deref()
+        .unwrap_or(".")
+        .to_lowercase();
+
+    let effective_max_depth = if recursive { max_depth } else { 1 };
+
+    let mut batch: Vec<DirectoryIndexEntry> = Vec::with_capacity(batch_size);
+    let mut total = 0usize;
+
+    for entry in WalkDir::new(root)
+        .max_depth(effective_max_depth)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        if cancelled.load(Ordering::Relaxed) || total >= max_entries {
+            break;
+        }
+        if entry.depth() == 0 {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy();
+        if !include_hidden && name.starts_with('.') {
+            continue;
+        }
+
+        let is_directory = entry.file_type().is_dir();
+        if (is_directory && !include_directories) || (!is_directory && !include_files) {
+            continue;
+        }
+
+        if search_pattern != "." && !name.to_lowercase().contains(&search_pattern) {
+            continue;
+        }
+
+        batch.push(DirectoryIndexEntry {
+            path: path_to_string(entry.path()),
+            name: name.to_string(),
+            is_directory,
+            depth: entry.depth(),
+        });
+        total += 1;
+        if batch.len() >= batch_size {
+            emit(std::mem::take(&mut batch));
+        }
+    }
+
+    let was_cancelled = cancelled.load(Ordering::Relaxed);
+    if !was_cancelled && !batch.is_empty() {
+        emit(batch);
+    }
+    (total, was_cancelled)
+}
+
+/// Walks `dir_path` on a background thread, emitting `dir:entry` batches and a
+/// final `dir:done` to the calling window. Returns a token for `file_cancel_index`.
+pub fn file_index_directory(
+    app: &AppHandle,
+    window: &WebviewWindow,
+    state: &State<'_, AppState>,
+    dir_path: String,
+    options: Option<Value>,
+) -> Result<String> {
+    let path = normalize_path(&dir_path);
+    if !is_allowed(state, &path) {
+        return Err(DromeError::Message("Path not allowed".into()));
+    }
+    if !path.is_dir() {
+        return Err(DromeError::Message("Path is not a directory".into()));
+    }
+    let options = DirectoryListOptions::parsed(options);
+    let (token, cancelled) = register_index_job()?;
+
+    let app = app.clone();
+    let window_label = window.label().to_string();
+    let job_token = token.clone();
+    std::thread::spawn(move || {
+        let (total, was_cancelled) = walk_directory_index(&path, &options, &cancelled, |entries| {
+            emit_to_window(
+                &app,
+                &window_label,
+                "dir:entry",
+                serde_json::json!({ "token": job_token, "entries": entries }),
+            );
+        });
+
+        finish_index_job(&job_token);
+        emit_to_window(
+            &app,
+            &window_label,
+            "dir:done",
+            serde_json::json!({ "token": job_token, "total": total, "cancelled": was_cancelled }),
+        );
+    });
+
+    Ok(token)
+}
+
+pub fn file_cancel_index(token: String) -> Result<bool> {
+    let job = index_jobs()
+        .lock()
+        .ok()
+        .and_then(|mut jobs| jobs.remove(&token));
+    match job {
+        Some(cancelled) => {
+            cancelled.store(true, Ordering::Relaxed);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
 pub fn file_check_file_name(
     state: &State<'_, AppState>,
     dir_path: String,
@@ -1294,80 +1591,83 @@ fn scan_notes_dir(base: &Path, current: &Path, depth: usize) -> Result<Vec<Notes
 
     let mut out = Vec::new();
     for entry in entries {
-        let name_os = entry.file_name();
-        let name = name_os.to_string_lossy().to_string();
-        if name.starts_with('.') {
+        if entry.file_name().to_string_lossy().starts_with('.') {
             continue;
         }
+        if let Some(node) = notes_node(base, &entry.path(), depth)? {
+            out.push(node);
+        }
+    }
 
-        let path = entry.path();
-        let meta = match fs::metadata(&path) {
-            Ok(m) => m,
-            Err(_) => continue,
-        };
+    Ok(out)
+}
 
-        let rel = path.strip_prefix(base).unwrap_or(&path);
-        let rel_s = rel.to_string_lossy().replace('\\', "/");
-        let tree_path_dir = format!("/{}", rel_s.trim_start_matches('/'));
-
-        if meta.is_dir() {
-            let external = path_to_string(&path);
-            let id = format!("{:x}", md5::compute(external.as_bytes()));
-            let children = scan_notes_dir(base, &path, depth + 1)?;
-            out.push(NotesTreeNode {
-                id,
-                name,
-                node_type: "folder".into(),
-                tree_path: tree_path_dir,
-                external_path: external,
-                children: Some(children),
-                created_at: metadata_created_iso(&meta),
-                updated_at: metadata_modified_iso(&meta),
-            });
-            continue;
-        }
+fn notes_node(base: &Path, path: &Path, depth: usize) -> Result<Option<NotesTreeNode>> {
+    let name = path
+        .file_name()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let meta = match fs::metadata(path) {
+        Ok(m) => m,
+        Err(_) => return Ok(None),
+    };
 
-        if meta.is_file() {
-            if ext_lower(&path) != ".md" {
-                continue;
-            }
+    let rel = path.strip_prefix(base).unwrap_or(path);
+    let rel_s = rel.to_string_lossy().replace('\\', "/");
+    let tree_path_dir = format!("/{}", rel_s.trim_start_matches('/'));
 
-            let external = path_to_string(&path);
-            let id = format!("{:x}", md5::compute(external.as_bytes()));
-            let file_stem = path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or(&name)
-                .to_string();
-
-            let dir_rel = rel
-                .parent()
-                .map(|p| p.to_string_lossy().replace('\\', "/"))
-                .unwrap_or_default();
-            let tree_path = if dir_rel.is_empty() || dir_rel == "." {
-                format!("/{file_stem}")
-            } else {
-                format!(
-                    "/{}/{}",
-                    dir_rel.trim_start_matches("./").trim_start_matches('/'),
-                    file_stem
-                )
-            };
-
-            out.push(NotesTreeNode {
-                id,
-                name: file_stem,
-                node_type: "file".into(),
-                tree_path,
-                external_path: external,
-                children: None,
-                created_at: metadata_created_iso(&meta),
-                updated_at: metadata_modified_iso(&meta),
-            });
-        }
+    if meta.is_dir() {
+        let external = path_to_string(path);
+        let id = format!("{:x}", md5::compute(external.as_bytes()));
+        let children = scan_notes_dir(base, path, depth + 1)?;
+        return Ok(Some(NotesTreeNode {
+            id,
+            name,
+            node_type: "folder".into(),
+            tree_path: tree_path_dir,
+            external_path: external,
+            children: Some(children),
+            created_at: metadata_created_iso(&meta),
+            updated_at: metadata_modified_iso(&meta),
+        }));
     }
 
-    Ok(out)
+    if !meta.is_file() || ext_lower(path) != ".md" {
+        return Ok(None);
+    }
+
+    let external = path_to_string(path);
+    let id = format!("{:x}", md5::compute(external.as_bytes()));
+    let file_stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(&name)
+        .to_string();
+
+    let dir_rel = rel
+        .parent()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default();
+    let tree_path = if dir_rel.is_empty() || dir_rel == "." {
+        format!("/{file_stem}")
+    } else {
+        format!(
+            "/{}/{}",
+            dir_rel.trim_start_matches("./").trim_start_matches('/'),
+            file_stem
+        )
+    };
+
+    Ok(Some(NotesTreeNode {
+        id,
+        name: file_stem,
+        node_type: "file".into(),
+        tree_path,
+        external_path: external,
+        children: None,
+        created_at: metadata_created_iso(&meta),
+        updated_at: metadata_modified_iso(&meta),
+    }))
 }
 
 pub fn file_get_directory_structure(
@@ -1382,6 +1682,153 @@ pub fn file_get_directory_structure(
     scan_notes_dir(&base, &base, 0)
 }
 
+/// Resolves a notes tree path (`/folder/note`) to the folder or `.md` file it
+/// names under `base`. The root is addressed as `/`.
+fn notes_resolve_tree_path(base: &Path, tree_path: &str) -> Result<PathBuf> {
+    let mut rel = PathBuf::new();
+    for segment in tree_path.split(['/', '\\']).filter(|s| !s.is_empty()) {
+        // Hidden entries (and `.`/`..`) never appear in the scanned tree.
+        if segment.starts_with('.') {
+            return Err(DromeError::Message(format!(
+                "Invalid tree path: {tree_path}"
+            )));
+        }
+        rel.push(segment);
+    }
+    if rel.as_os_str().is_empty() {
+        return Ok(base.to_path_buf());
+    }
+
+    let dir = base.join(&rel);
+    if dir.is_dir() {
+        return Ok(dir);
+    }
+    let mut file = dir.into_os_string();
+    file.push(".md");
+    let file = PathBuf::from(file);
+    if file.is_file() {
+        return Ok(file);
+    }
+    Err(DromeError::Message(format!("Note not found: {tree_path}")))
+}
+
+fn notes_base(state: &State<'_, AppState>, dir_base: &str) -> Result<PathBuf> {
+    let base = normalize_path(dir_base);
+    if !is_allowed(state, &base) {
+        return Err(DromeError::Message("Path not allowed".into()));
+    }
+    if !base.is_dir() {
+        return Err(DromeError::Message("Notes directory does not exist".into()));
+    }
+    Ok(base)
+}
+
+pub fn notes_create(
+    state: &State<'_, AppState>,
+    dir_base: String,
+    tree_path: String,
+    name: String,
+    is_folder: bool,
+) -> Result<NotesTreeNode> {
+    let base = notes_base(state, &dir_base)?;
+    notes_create_in(&base, &tree_path, &name, is_folder, |p| {
+        is_allowed(state, p)
+    })
+}
+
+fn notes_create_in(
+    base: &Path,
+    tree_path: &str,
+    name: &str,
+    is_folder: bool,
+    allowed: impl Fn(&Path) -> bool,
+) -> Result<NotesTreeNode> {
+    let parent = notes_resolve_tree_path(base, tree_path)?;
+    if !parent.is_dir() {
+        return Err(DromeError::Message(format!("Not a folder: {tree_path}")));
+    }
+
+    let safe = unique_name(&parent, name, !is_folder);
+    if safe.starts_with('.') {
+        return Err(DromeError::Message(format!(
+            "Note names cannot start with '.': {name}"
+        )));
+    }
+    let target = if is_folder {
+        parent.join(&safe)
+    } else {
+        parent.join(format!("{safe}.md"))
+    };
+    if !allowed(&target) {
+        return Err(DromeError::Message("Path not allowed".into()));
+    }
+    if is_folder {
+        fs::create_dir(&target)?;
+    } else {
+        fs::write(&target, "")?;
+    }
+
+    notes_node(base, &target, 0)?.ok_or_else(|| DromeError::Message("Failed to create note".into()))
+}
+
+pub fn notes_move(
+    state: &State<'_, AppState>,
+    dir_base: String,
+    from_tree_path: String,
+    to_tree_path: String,
+) -> Result<NotesTreeNode> {
+    let base = notes_base(state, &dir_base)?;
+    notes_move_in(&base, &from_tree_path, &to_tree_path, |p| {
+        is_allowed(state, p)
+    })
+}
+
+fn notes_move_in(
+    base: &Path,
+    from_tree_path: &str,
+    to_tree_path: &str,
+    allowed: impl Fn(&Path) -> bool,
+) -> Result<NotesTreeNode> {
+    let src = notes_resolve_tree_path(base, from_tree_path)?;
+    let dest_dir = notes_resolve_tree_path(base, to_tree_path)?;
+    if src == base {
+        return Err(DromeError::Message("Cannot move the notes root".into()));
+    }
+    if !dest_dir.is_dir() {
+        return Err(DromeError::Message(format!("Not a folder: {to_tree_path}")));
+    }
+    if dest_dir.starts_with(&src) {
+        return Err(DromeError::Message(
+            "Cannot move a folder into itself".into(),
+        ));
+    }
+    if src.parent() == Some(dest_dir.as_path()) {
+        return notes_node(base, &src, 0)?
+            .ok_or_else(|| DromeError::Message("Note not found".into()));
+    }
+
+    let is_file = src.is_file();
+    let stem = if is_file {
+        src.file_stem()
+    } else {
+        src.file_name()
+    }
+    .map(|s| s.to_string_lossy().to_string())
+    .unwrap_or_default();
+    let safe = unique_name(&dest_dir, &stem, is_file);
+    let dest = if is_file {
+        dest_dir.join(format!("{safe}.md"))
+    } else {
+        dest_dir.join(&safe)
+    };
+    if !allowed(&dest) {
+        return Err(DromeError::Message("Path not allowed".into()));
+    }
+    fs::rename(&src, &dest)?;
+
+    notes_node(base, &dest, 0)?.ok_or_else(|| DromeError::Message("Note not found".into()))
+}
+
 pub fn file_open_with_relative_path(
     state: &State<'_, AppState>,
     file: StoredFileMetadata,
@@ -1391,9 +1838,16 @@ pub fn file_open_with_relative_path(
     } else {
         format!("{}{}", file.id, file.ext)
     };
-    let path = files_dir(state).join(file_name);
+    if file_name.contains(['/', '\\']) || file_name.starts_with('.') {
+        return Err(DromeError::Message(format!(
+            "Invalid file name: {file_name}"
+        )));
+    }
+    let files_dir = files_dir(state);
+    let path = files_dir.join(file_name);
     if path.exists() {
-        open(None, path_to_string(&path), None).map_err(|e| DromeError::Message(e.to_string()))?;
+        let target = validate_open_target(&[files_dir], &path_to_string(&path))?;
+        open(None, target, None).map_err(|e| DromeError::Message(e.to_string()))?;
     }
     Ok(())
 }
@@ -1581,6 +2035,117 @@ pub fn file_base64_image(state: &State<'_, AppState>, id: String) -> Result<Base
     })
 }
 
+const THUMBNAIL_MIN_DIM: u32 = 16;
+const THUMBNAIL_MAX_DIM: u32 = 4096;
+
+fn thumbnail_cache_dir(state: &State<'_, AppState>) -> PathBuf {
+    temp_dir(state).join("Thumbnails")
+}
+
+/// Cache stem for a thumbnail of `id`. The source length and mtime are part of
+/// the key so that overwriting a file under the same id misses the cache.
+fn thumbnail_cache_stem(id: &str, max_dim: u32, meta: &fs::Metadata) -> String {
+    let mtime = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    format!("{id}_{max_dim}_{}_{mtime}", meta.len())
+}
+
+/// Removes cached thumbnails whose stem starts with `prefix`.
+fn remove_cached_thumbnails(cache_dir: &Path, prefix: &str) {
+    let Ok(entries) = fs::read_dir(cache_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with(prefix) {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Decodes `source` and encodes it scaled to fit within `max_dim`, as PNG when
+/// it has alpha and JPEG otherwise. Returns the bytes, extension and mime.
+fn render_thumbnail(source: &Path, max_dim: u32) -> Result<(Vec<u8>, &'static str, &'static str)> {
+    let img = image::ImageReader::open(source)?
+        .with_guessed_format()?
+        .decode()
+        .map_err(|e| DromeError::Message(format!("Unsupported or corrupt image: {e}")))?;
+    let thumb = if img.width() > max_dim || img.height() > max_dim {
+        img.thumbnail(max_dim, max_dim)
+    } else {
+        img
+    };
+
+    let (format, ext, mime) = if thumb.color().has_alpha() {
+        (image::ImageFormat::Png, "png", "image/png")
+    } else {
+        (image::ImageFormat::Jpeg, "jpg", "image/jpeg")
+    };
+    let thumb = if format == image::ImageFormat::Jpeg {
+        image::DynamicImage::ImageRgb8(thumb.to_rgb8())
+    } else {
+        thumb
+    };
+    let mut bytes = std::io::Cursor::new(Vec::new());
+    thumb
+        .write_to(&mut bytes, format)
+        .map_err(|e| DromeError::Message(e.to_string()))?;
+    Ok((bytes.into_inner(), ext, mime))
+}
+
+/// Returns a downscaled copy of a stored image that fits within `max_dim` on
+/// both sides. Results are cached under `Temp/Thumbnails`.
+pub fn file_thumbnail(
+    state: &State<'_, AppState>,
+    id: String,
+    max_dim: u32,
+) -> Result<Base64ImageResult> {
+    if id.is_empty() || id.contains(['/', '\\']) || id.contains("..") {
+        return Err(DromeError::Message("Invalid file id".into()));
+    }
+    if !(THUMBNAIL_MIN_DIM..=THUMBNAIL_MAX_DIM).contains(&max_dim) {
+        return Err(DromeError::Message(format!(
+            "Thumbnail size must be between {THUMBNAIL_MIN_DIM} and {THUMBNAIL_MAX_DIM}, got {max_dim}"
+        )));
+    }
+    let source = files_dir(state).join(&id);
+    let meta = fs::metadata(&source).ok().filter(|m| m.is_file());
+    let Some(meta) = meta else {
+        return Err(DromeError::Message(format!("File not found: {id}")));
+    };
+
+    let cache_dir = thumbnail_cache_dir(state);
+    let stem = thumbnail_cache_stem(&id, max_dim, &meta);
+    for (ext, mime) in [("jpg", "image/jpeg"), ("png", "image/png")] {
+        let cached = cache_dir.join(format!("{stem}.{ext}"));
+        if let Ok(bytes) = fs::read(&cached) {
+            let base64 = general_purpose::STANDARD.encode(&bytes);
+            return Ok(Base64ImageResult {
+                mime: mime.into(),
+                data: format!("data:{mime};base64,{base64}"),
+                base64,
+            });
+        }
+    }
+
+    let (bytes, ext, mime) = render_thumbnail(&source, max_dim)?;
+
+    // Drop thumbnails rendered from an earlier version of this file.
+    remove_cached_thumbnails(&cache_dir, &format!("{id}_{max_dim}_"));
+    ensure_dir(&cache_dir)?;
+    let _ = fs::write(cache_dir.join(format!("{stem}.{ext}")), &bytes);
+
+    let base64 = general_purpose::STANDARD.encode(&bytes);
+    Ok(Base64ImageResult {
+        mime: mime.into(),
+        data: format!("data:{mime};base64,{base64}"),
+        base64,
+    })
+}
+
 pub fn file_copy(state: &State<'_, AppState>, id: String, dest_path: String) -> Result<()> {
     let src = files_dir(state).join(id);
     let dest = normalize_path(&dest_path);
@@ -1594,18 +2159,118 @@ pub fn file_copy(state: &State<'_, AppState>, id: String, dest_path: String) ->
     Ok(())
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct DownloadOptions {
+    max_bytes: Option<u64>,
+    accept_content_types: Option<Vec<String>>,
+}
+
+impl DownloadOptions {
+    fn parse(options: Option<Value>) -> Result<Self> {
+        let Some(options) = options else {
+            return Ok(Self::default());
+        };
+        serde_json::from_value(options)
+            .map_err(|e| DromeError::Message(format!("Invalid download options: {e}")))
+    }
+
+    /// Matches the response's media type against the allowlist. Entries may be
+    /// exact (`application/pdf`) or wildcards (`image/*`); parameters such as
+    /// `charset` are ignored on both sides.
+    fn accepts(&self, content_type: Option<&str>) -> bool {
+        let Some(allowed) = self.accept_content_types.as_ref().filter(|v| !v.is_empty()) else {
+            return true;
+        };
+        let Some(actual) = content_type.map(media_type) else {
+            return false;
+        };
+        allowed.iter().map(|a| media_type(a)).any(|a| {
+            a == "*/*"
+                || a == actual
+                || a.strip_suffix("/*")
+                    .is_some_and(|prefix| actual.split('/').next() == Some(prefix))
+        })
+    }
+}
+
+fn media_type(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Streams `reader` into `dest`, removing the partial file if the body exceeds
+/// `max_bytes` or the copy fails.
+fn copy_with_limit(reader: &mut impl Read, dest: &Path, max_bytes: Option<u64>) -> Result<()> {
+    let result = copy_with_limit_inner(reader, dest, max_bytes);
+    if result.is_err() {
+        let _ = fs::remove_file(dest);
+    }
+    result
+}
+
+fn copy_with_limit_inner(
+    reader: &mut impl Read,
+    dest: &Path,
+    max_bytes: Option<u64>,
+) -> Result<()> {
+    let mut out = fs::File::create(dest)?;
+    let mut buf = [0u8; 64 * 1024];
+    let mut written: u64 = 0;
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        written += n as u64;
+        if let Some(limit) = max_bytes {
+            if written > limit {
+                return Err(DromeError::Message(format!(
+                    "Download exceeds the maximum size of {limit} bytes"
+                )));
+            }
+        }
+        out.write_all(&buf[..n])?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
 pub fn file_download(
     state: &State<'_, AppState>,
     url: String,
     is_use_content_type: Option<bool>,
+    options: Option<Value>,
 ) -> Result<StoredFileMetadata> {
+    let options = DownloadOptions::parse(options)?;
+    download_to(&files_dir(state), &url, is_use_content_type, &options)
+}
+
+/// Downloads `url` into `files_dir` under a fresh uuid name. Type and declared
+/// size are checked before the body is read.
+fn download_to(
+    files_dir: &Path,
+    url: &str,
+    is_use_content_type: Option<bool>,
+    options: &DownloadOptions,
+) -> Result<StoredFileMetadata> {
+    // Decompression stays off here: reqwest drops Content-Length from decoded
+    // responses, which would disable the early size check, and `max_bytes`
+    // should bound the bytes actually written to disk.
     let client = reqwest::blocking::Client::builder()
         .user_agent("Mozilla/5.0 (compatible; drome/0.1)")
+        .no_gzip()
+        .no_brotli()
+        .no_deflate()
         .build()
         .map_err(|e| DromeError::Message(e.to_string()))?;
 
-    let resp = client
-        .get(&url)
+    let mut resp = client
+        .get(url)
         .send()
         .map_err(|e| DromeError::Message(e.to_string()))?;
     if !resp.status().is_success() {
@@ -1616,10 +2281,6 @@ pub fn file_download(
     }
 
     let headers = resp.headers().clone();
-    let bytes = resp
-        .bytes()
-        .map_err(|e| DromeError::Message(e.to_string()))?
-        .to_vec();
 
     // Filename from Content-Disposition or URL path.
     let mut filename = headers
@@ -1644,6 +2305,20 @@ pub fn file_download(
         .and_then(|v| v.to_str().ok())
         .map(|s| s.to_string());
 
+    if !options.accepts(content_type.as_deref()) {
+        return Err(DromeError::Message(format!(
+            "Download rejected: content type {} is not allowed",
+            content_type.as_deref().unwrap_or("(none)")
+        )));
+    }
+    if let (Some(limit), Some(len)) = (options.max_bytes, resp.content_length()) {
+        if len > limit {
+            return Err(DromeError::Message(format!(
+                "Download rejected: {len} bytes exceeds the maximum size of {limit} bytes"
+            )));
+        }
+    }
+
     let needs_ext = is_use_content_type.unwrap_or(false) || !filename.contains('.');
     if needs_ext {
         if let Some(ct) = content_type.as_deref() {
@@ -1663,10 +2338,10 @@ pub fn file_download(
         .map(|s| format!(".{}", s.to_lowercase()))
         .unwrap_or_else(|| ".bin".into());
 
-    ensure_dir(&files_dir(state))?;
+    ensure_dir(files_dir)?;
     let uuid = Uuid::new_v4().to_string();
-    let dest = files_dir(state).join(format!("{uuid}{ext}"));
-    fs::write(&dest, &bytes)?;
+    let dest = files_dir.join(format!("{uuid}{ext}"));
+    copy_with_limit(&mut resp, &dest, options.max_bytes)?;
 
     let meta = fs::metadata(&dest)?;
     let file_type = file_type_by_ext_or_content(&dest);
@@ -1688,13 +2363,31 @@ pub fn file_download(
     })
 }
 
-pub fn file_show_in_folder(_app: &AppHandle, path: String) -> Result<()> {
-    let path = normalize_path(&path);
+/// Resolves the folder `file_show_in_folder` opens for `target`: the target
+/// itself when it is a directory, otherwise its parent. Only that folder has
+/// to be non-launchable (e.g. not a `.app` bundle); the selected file may be
+/// an executable.
+fn show_in_folder_target(roots: &[PathBuf], target: &str) -> Result<PathBuf> {
+    let target = target.trim();
+    if url_scheme(target).is_some_and(|s| !s.eq_ignore_ascii_case("file")) {
+        return Err(DromeError::Message("Path not allowed".into()));
+    }
+    let path = resolve_open_path(roots, target)?;
     let folder = if path.is_dir() {
         path
     } else {
         path.parent().unwrap_or(&path).to_path_buf()
     };
+    refuse_launchable(&folder)?;
+    Ok(folder)
+}
+
+pub fn file_show_in_folder(
+    _app: &AppHandle,
+    state: &State<'_, AppState>,
+    path: String,
+) -> Result<()> {
+    let folder = show_in_folder_target(&allowed_roots(state), &path)?;
     open(None, path_to_string(&folder), None).map_err(|e| DromeError::Message(e.to_string()))?;
     Ok(())
 }
@@ -1722,14 +2415,18 @@ fn kind_to_event_type(kind: &EventKind) -> Option<&'static str> {
     }
 }
 
-fn emit_file_change(app: &AppHandle, window_label: &str, payload: Value) {
+fn emit_to_window(app: &AppHandle, window_label: &str, event: &str, payload: Value) {
     if let Some(win) = app.get_webview_window(window_label) {
-        let _ = win.emit("file-change", payload);
+        let _ = win.emit(event, payload);
     } else {
-        let _ = app.emit("file-change", payload);
+        let _ = app.emit(event, payload);
     }
 }
 
+fn emit_file_change(app: &AppHandle, window_label: &str, payload: Value) {
+    emit_to_window(app, window_label, "file-change", payload);
+}
+
 pub fn file_start_watcher(
     app: &AppHandle,
     window: &WebviewWindow,
@@ -1882,3 +2579,498 @@ pub fn file_batch_upload_markdown(
         skipped_files: skipped,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("drome-file-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::canonicalize(dir).unwrap()
+    }
+
+    #[test]
+    fn url_scheme_ignores_drive_letters() {
+        assert_eq!(url_scheme("https://example.com"), Some("https"));
+        assert_eq!(url_scheme("file:///tmp/a.txt"), Some("file"));
+        assert_eq!(url_scheme("javascript:alert(1)"), Some("javascript"));
+        assert_eq!(url_scheme("C:\\Users\\a.txt"), None);
+        assert_eq!(url_scheme("/tmp/a.txt"), None);
+    }
+
+    #[test]
+    fn open_target_accepts_allowed_file_and_http_urls() {
+        let root = scratch_dir();
+        let file = root.join("notes.txt");
+        fs::write(&file, "hello").unwrap();
+        let roots = vec![root.clone()];
+
+        assert_eq!(
+            validate_open_target(&roots, &path_to_string(&file)).unwrap(),
+            path_to_string(&file)
+        );
+        assert!(validate_open_target(&roots, "https://example.com/a").is_ok());
+        assert!(validate_open_target(&roots, "javascript:alert(1)").is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn open_target_refuses_disallowed_and_launchable_paths() {
+        let root = scratch_dir();
+        let outside = scratch_dir();
+        let foreign = outside.join("notes.txt");
+        fs::write(&foreign, "hello").unwrap();
+        let roots = vec![root.clone()];
+
+        assert!(validate_open_target(&roots, &path_to_string(&foreign)).is_err());
+        assert!(validate_open_target(&roots, &path_to_string(&root.join("missing.txt"))).is_err());
+
+        let script = root.join("run.sh");
+        fs::write(&script, "#!/bin/sh\n").unwrap();
+        assert!(
+            validate_open_target(&roots, &format!("file://{}", path_to_string(&script))).is_err()
+        );
+
+        let bundle = root.join("Evil.app");
+        fs::create_dir_all(&bundle).unwrap();
+        assert!(
+            validate_open_target(&roots, &format!("file://{}", path_to_string(&bundle))).is_err()
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let binary = root.join("tool");
+            fs::write(&binary, "").unwrap();
+            fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+            assert!(validate_open_target(&roots, &path_to_string(&binary)).is_err());
+        }
+
+        fs::remove_dir_all(&root).unwrap();
+        fs::remove_dir_all(&outside).unwrap();
+    }
+
+    #[test]
+    fn download_options_reject_malformed_input() {
+        assert!(DownloadOptions::parse(None).is_ok());
+        assert!(DownloadOptions::parse(Some(serde_json::json!({ "maxBytes": 1000 }))).is_ok());
+        assert!(DownloadOptions::parse(Some(serde_json::json!({ "maxBytes": "1000" }))).is_err());
+        assert!(DownloadOptions::parse(Some(serde_json::json!({ "maxByte": 1000 }))).is_err());
+    }
+
+    #[test]
+    fn download_rejects_disallowed_content_type() {
+        let options = DownloadOptions {
+            max_bytes: None,
+            accept_content_types: Some(vec!["image/*".into(), "application/pdf".into()]),
+        };
+        assert!(options.accepts(Some("image/png")));
+        assert!(options.accepts(Some("Application/PDF; charset=binary")));
+        assert!(!options.accepts(Some("text/html; charset=utf-8")));
+        assert!(!options.accepts(None));
+        assert!(DownloadOptions::default().accepts(Some("text/html")));
+    }
+
+    #[test]
+    fn download_over_size_limit_removes_partial_file() {
+        let root = scratch_dir();
+        let dest = root.join("body.bin");
+
+        let mut body = std::io::Cursor::new(vec![0u8; 200 * 1024]);
+        assert!(copy_with_limit(&mut body, &dest, Some(100 * 1024)).is_err());
+        assert!(!dest.exists());
+
+        let mut body = std::io::Cursor::new(vec![0u8; 1024]);
+        copy_with_limit(&mut body, &dest, Some(1024)).unwrap();
+        assert_eq!(fs::metadata(&dest).unwrap().len(), 1024);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// Serves one response on a local port; `head` is the header block without
+    /// the status line. Returns the download URL.
+    fn serve_download_once(head: &str, body: Vec<u8>) -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let head = format!("HTTP/1.1 200 OK\r\n{head}Connection: close\r\n\r\n");
+        std::thread::spawn(move || {
+            let (mut conn, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = conn.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            conn.write_all(head.as_bytes()).unwrap();
+            let _ = conn.write_all(&body);
+        });
+        format!("http://{addr}/files/report.pdf")
+    }
+
+    fn is_empty_dir(dir: &Path) -> bool {
+        fs::read_dir(dir).unwrap().next().is_none()
+    }
+
+    #[test]
+    fn download_stores_accepted_file() {
+        let root = scratch_dir();
+        let url = serve_download_once(
+            "Content-Type: application/pdf\r\nContent-Length: 4\r\n",
+            b"%PDF".to_vec(),
+        );
+        let options = DownloadOptions {
+            max_bytes: Some(1024),
+            accept_content_types: Some(vec!["application/pdf".into()]),
+        };
+
+        let meta = download_to(&root, &url, None, &options).unwrap();
+        assert_eq!((meta.origin_name.as_str(), meta.size), ("report.pdf", 4));
+        assert!(Path::new(&meta.path).starts_with(&root));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    // The servers below send headers only. Reading the body would fail with a
+    // truncation error, so the rejection message shows the body was never read.
+    #[test]
+    fn download_rejects_declared_oversize_before_reading_body() {
+        let root = scratch_dir();
+        let url = serve_download_once(
+            "Content-Type: application/pdf\r\nContent-Length: 2048\r\n",
+            Vec::new(),
+        );
+        let options = DownloadOptions {
+            max_bytes: Some(1024),
+            accept_content_types: None,
+        };
+
+        let err = download_to(&root, &url, None, &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("2048 bytes exceeds the maximum size of 1024 bytes"),
+            "{err}"
+        );
+        assert!(is_empty_dir(&root));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn download_rejects_content_type_before_reading_body() {
+        let root = scratch_dir();
+        let url = serve_download_once(
+            "Content-Type: text/html\r\nContent-Length: 2048\r\n",
+            Vec::new(),
+        );
+        let options = DownloadOptions {
+            max_bytes: None,
+            accept_content_types: Some(vec!["application/pdf".into()]),
+        };
+
+        let err = download_to(&root, &url, None, &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("content type text/html is not allowed"),
+            "{err}"
+        );
+        assert!(is_empty_dir(&root));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn download_without_length_over_limit_leaves_no_file() {
+        let root = scratch_dir();
+        let url = serve_download_once("Content-Type: application/pdf\r\n", vec![0u8; 4096]);
+        let options = DownloadOptions {
+            max_bytes: Some(1024),
+            accept_content_types: None,
+        };
+
+        assert!(download_to(&root, &url, None, &options).is_err());
+        assert!(is_empty_dir(&root));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn thumbnail_fits_within_bound() {
+        let root = scratch_dir();
+        let source = root.join("wide.png");
+        image::RgbImage::new(800, 400).save(&source).unwrap();
+
+        let (bytes, ext, mime) = render_thumbnail(&source, 100).unwrap();
+        assert_eq!((ext, mime), ("jpg", "image/jpeg"));
+        let thumb = image::load_from_memory(&bytes).unwrap();
+        assert_eq!((thumb.width(), thumb.height()), (100, 50));
+
+        let (bytes, _, _) = render_thumbnail(&source, 1000).unwrap();
+        let thumb = image::load_from_memory(&bytes).unwrap();
+        assert_eq!((thumb.width(), thumb.height()), (800, 400));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn thumbnail_of_corrupt_image_is_a_clear_error() {
+        let root = scratch_dir();
+        let source = root.join("broken.png");
+        fs::write(&source, b"not an image").unwrap();
+
+        let err = render_thumbnail(&source, 100).unwrap_err().to_string();
+        assert!(err.starts_with("Unsupported or corrupt image"), "{err}");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn thumbnail_cache_key_tracks_source_and_is_purged() {
+        let root = scratch_dir();
+        let source = root.join("a.png");
+        fs::write(&source, b"1234").unwrap();
+        let before = thumbnail_cache_stem("a.png", 256, &fs::metadata(&source).unwrap());
+        fs::write(&source, b"123456").unwrap();
+        let after = thumbnail_cache_stem("a.png", 256, &fs::metadata(&source).unwrap());
+        assert_ne!(before, after);
+
+        fs::write(root.join(format!("{before}.jpg")), b"").unwrap();
+        fs::write(root.join("b.png_256_1_1.jpg"), b"").unwrap();
+        remove_cached_thumbnails(&root, "a.png_");
+        assert!(!root.join(format!("{before}.jpg")).exists());
+        assert!(root.join("b.png_256_1_1.jpg").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn index_walk_honours_list_directory_options() {
+        let root = scratch_dir();
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("Readme.md"), "").unwrap();
+        fs::write(root.join("other.txt"), "").unwrap();
+        fs::write(root.join("sub/readme-2.md"), "").unwrap();
+        fs::write(root.join(".git/readme.md"), "").unwrap();
+        fs::write(root.join(".readme.md"), "").unwrap();
+
+        let walk = |options: Value| {
+            let options = DirectoryListOptions::parsed(Some(options));
+            let mut names = Vec::new();
+            walk_directory_index(&root, &options, &AtomicBool::new(false), |entries| {
+                names.extend(entries.into_iter().map(|e| e.name))
+            });
+            names.sort();
+            names
+        };
+
+        // Hidden entries are skipped by name, as in `file_list_directory`.
+        assert_eq!(
+            walk(serde_json::json!({ "searchPattern": "README" })),
+            vec!["Readme.md", "readme-2.md", "readme.md"]
+        );
+        assert_eq!(
+            walk(serde_json::json!({ "recursive": false, "searchPattern": "readme" })),
+            vec!["Readme.md"]
+        );
+        // Malformed options fall back to the defaults, like `listDirectory`.
+        assert_eq!(walk(serde_json::json!({ "maxEntries": "1" })).len(), 5);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn index_jobs_are_cancellable_until_finished() {
+        let (token, cancelled) = register_index_job().unwrap();
+        assert!(file_cancel_index(token.clone()).unwrap());
+        assert!(cancelled.load(Ordering::Relaxed));
+        assert!(!file_cancel_index(token).unwrap());
+
+        let (token, cancelled) = register_index_job().unwrap();
+        finish_index_job(&token);
+        assert!(!file_cancel_index(token).unwrap());
+        assert!(!cancelled.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn index_walk_emits_all_batches() {
+        let root = scratch_dir();
+        for i in 0..12 {
+            fs::write(root.join(format!("note-{i}.md")), "").unwrap();
+        }
+        fs::write(root.join(".hidden.md"), "").unwrap();
+        let options = DirectoryListOptions {
+            batch_size: Some(5),
+            ..Default::default()
+        };
+
+        let mut batches = Vec::new();
+        let (total, cancelled) =
+            walk_directory_index(&root, &options, &AtomicBool::new(false), |entries| {
+                batches.push(entries.len())
+            });
+
+        assert_eq!((total, cancelled), (12, false));
+        assert_eq!(batches, vec![5, 5, 2]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn index_walk_stops_emitting_after_cancel() {
+        let root = scratch_dir();
+        for i in 0..50 {
+            fs::write(root.join(format!("note-{i}.md")), "").unwrap();
+        }
+        let options = DirectoryListOptions {
+            batch_size: Some(5),
+            ..Default::default()
+        };
+
+        let cancelled = AtomicBool::new(false);
+        let mut emitted = 0;
+        let (total, was_cancelled) = walk_directory_index(&root, &options, &cancelled, |entries| {
+            emitted += entries.len();
+            cancelled.store(true, Ordering::Relaxed);
+        });
+
+        assert!(was_cancelled);
+        assert_eq!(emitted, 5);
+        assert!(total < 50);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn notes_create_nested_and_move_between_folders() {
+        let base = scratch_dir();
+        let inside = |p: &Path| p.starts_with(&base);
+
+        let a = notes_create_in(&base, "/", "a", true, inside).unwrap();
+        assert_eq!(
+            (a.tree_path.as_str(), a.node_type.as_str()),
+            ("/a", "folder")
+        );
+        notes_create_in(&base, "/a", "b", true, inside).unwrap();
+        notes_create_in(&base, "/", "c", true, inside).unwrap();
+
+        let note = notes_create_in(&base, "/a/b", "Hello", false, inside).unwrap();
+        assert_eq!(
+            (note.tree_path.as_str(), note.node_type.as_str()),
+            ("/a/b/Hello", "file")
+        );
+        assert!(base.join("a/b/Hello.md").is_file());
+
+        let again = notes_create_in(&base, "/a/b", "Hello", false, inside).unwrap();
+        assert_eq!(again.tree_path, "/a/b/Hello1");
+
+        let moved = notes_move_in(&base, "/a/b/Hello", "/c", inside).unwrap();
+        assert_eq!(moved.tree_path, "/c/Hello");
+        assert!(base.join("c/Hello.md").is_file());
+        assert!(!base.join("a/b/Hello.md").exists());
+
+        let folder = notes_move_in(&base, "/a/b", "/c", inside).unwrap();
+        assert_eq!(folder.tree_path, "/c/b");
+        assert!(base.join("c/b/Hello1.md").is_file());
+        assert!(notes_move_in(&base, "/c", "/c/b", inside).is_err());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn notes_reject_hidden_segments() {
+        let base = scratch_dir();
+        fs::create_dir_all(base.join(".git")).unwrap();
+        let inside = |p: &Path| p.starts_with(&base);
+
+        assert!(notes_resolve_tree_path(&base, "/.git").is_err());
+        assert!(notes_resolve_tree_path(&base, "/../etc").is_err());
+        assert!(notes_create_in(&base, "/.git", "hooks", true, inside).is_err());
+        assert!(notes_create_in(&base, "/", ".secret", false, inside).is_err());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn open_target_refuses_every_launchable_extension() {
+        let root = scratch_dir();
+        let roots = vec![root.clone()];
+        let named = [
+            "js",
+            "jse",
+            "vbe",
+            "hta",
+            "msc",
+            "psm1",
+            "scf",
+            "appref-ms",
+            "application",
+            "py",
+            "pyw",
+            "terminal",
+            "tool",
+            "scpt",
+            "workflow",
+            "pkg",
+            "mpkg",
+            "run",
+        ];
+        for ext in LAUNCHABLE_EXTS.iter().chain(named.iter()) {
+            assert!(
+                LAUNCHABLE_EXTS.contains(ext),
+                "{ext} missing from LAUNCHABLE_EXTS"
+            );
+            for name in [
+                format!("target.{ext}"),
+                format!("TARGET.{}", ext.to_uppercase()),
+            ] {
+                let file = root.join(&name);
+                fs::write(&file, "").unwrap();
+                assert!(
+                    validate_open_target(&roots, &path_to_string(&file)).is_err(),
+                    "{name} should be refused"
+                );
+                fs::remove_file(&file).unwrap();
+            }
+        }
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn website_urls_are_limited_to_http_and_mailto() {
+        assert!(validate_website_url("https://example.com").is_ok());
+        assert!(validate_website_url("HTTP://example.com").is_ok());
+        assert!(validate_website_url("mailto:support@example.com").is_ok());
+        assert!(validate_website_url("file:///etc/passwd").is_err());
+        assert!(validate_website_url("/Applications/Evil.app").is_err());
+        assert!(validate_website_url("smb://host/share").is_err());
+    }
+
+    #[test]
+    fn show_in_folder_allows_executables_but_not_bundles() {
+        let root = scratch_dir();
+        let roots = vec![root.clone()];
+        let script = root.join("run.sh");
+        fs::write(&script, "#!/bin/sh\n").unwrap();
+        let bundle = root.join("Evil.app");
+        fs::create_dir_all(&bundle).unwrap();
+        fs::write(bundle.join("Info.plist"), "").unwrap();
+
+        assert_eq!(
+            show_in_folder_target(&roots, &path_to_string(&script)).unwrap(),
+            root
+        );
+        assert!(show_in_folder_target(&roots, &path_to_string(&bundle)).is_err());
+        assert!(
+            show_in_folder_target(&roots, &path_to_string(&bundle.join("Info.plist"))).is_err()
+        );
+        assert!(show_in_folder_target(&roots, "https://example.com").is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,7 +1,9 @@
 use serde_json::Value;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::sync::MutexGuard;
 use tauri::{AppHandle, Manager, State};
 
+use crate::commands::system;
 use crate::error::{DromeError, Result};
 use crate::state::AppState;
 
@@ -32,15 +34,14 @@ fn is_allowed(app: &AppHandle, state: &State<'_, AppState>, path: &Path) -> bool
         }
     }
 
-    let dirs = state.allowed_dirs.lock().ok();
-    if let Some(dirs) = dirs {
-        for allowed in dirs.iter() {
-            if path.starts_with(allowed) {
-                return true;
-            }
-        }
-    }
-    false
+    state
+        .allowed_dirs
+        .lock()
+        .is_ok_and(|dirs| is_in_allowed_dirs(&dirs, path))
+}
+
+fn is_in_allowed_dirs(dirs: &[PathBuf], path: &Path) -> bool {
+    dirs.iter().any(|allowed| path.starts_with(allowed))
 }
 
 pub fn fs_read(
@@ -87,3 +88,173 @@ pub fn fs_read_text(
 
     Ok(std::fs::read_to_string(path)?)
 }
+
+fn lock_allowed_dirs<'a>(state: &'a State<'_, AppState>) -> Result<MutexGuard<'a, Vec<PathBuf>>> {
+    state.allowed_dirs.lock().map_err(|_| {
+        DromeError::Message("Allowed directories are unavailable (lock poisoned)".into())
+    })
+}
+
+/// Parses a renderer-supplied directory, rejecting empty, relative and `..` paths
+/// (an empty path would match everything in `Path::starts_with`).
+fn parse_allowed_dir(dir: &str) -> Result<PathBuf> {
+    if dir.trim().is_empty() {
+        return Err(DromeError::Message("Directory must not be empty".into()));
+    }
+    let path = normalize_path(dir);
+    if !path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+        return Err(DromeError::Message(format!(
+            "Directory must be an absolute path: {dir}"
+        )));
+    }
+    Ok(path)
+}
+
+/// Narrows `current` to `requested`. New directories can only be granted through
+/// native dialogs or drag-drop, so every entry must already be covered by `current`.
+fn narrow_allowed_dirs(current: &[PathBuf], requested: Vec<String>) -> Result<Vec<PathBuf>> {
+    let mut next: Vec<PathBuf> = Vec::new();
+    for dir in requested {
+        let path = parse_allowed_dir(&dir)?;
+        if !path.is_dir() {
+            return Err(DromeError::Message(format!(
+                "Directory does not exist: {dir}"
+            )));
+        }
+        if !is_in_allowed_dirs(current, &path) {
+            return Err(DromeError::Message(format!(
+                "Directory is not currently allowed: {dir}"
+            )));
+        }
+        if !next.contains(&path) {
+            next.push(path);
+        }
+    }
+    Ok(next)
+}
+
+/// Removes `dir` and any allowed directory nested inside it. Revoking a directory
+/// that is only reachable through a broader grant is an error, since it would
+/// stay accessible, and so is revoking a directory that was never granted.
+fn revoke_from_allowed_dirs(current: &[PathBuf], dir: &Path) -> Result<Vec<PathBuf>> {
+    if let Some(broader) = current
+        .iter()
+        .find(|d| dir.starts_with(d) && d.as_path() != dir)
+    {
+        return Err(DromeError::Message(format!(
+            "{} is covered by the broader grant {}; revoke that directory instead",
+            dir.to_string_lossy(),
+            broader.to_string_lossy()
+        )));
+    }
+    let next: Vec<PathBuf> = current
+        .iter()
+        .filter(|d| !d.starts_with(dir))
+        .cloned()
+        .collect();
+    if next.len() == current.len() {
+        return Err(DromeError::Message(format!(
+            "Directory is not currently allowed: {}",
+            dir.to_string_lossy()
+        )));
+    }
+    Ok(next)
+}
+
+pub fn fs_list_allowed_dirs(state: &State<'_, AppState>) -> Result<Vec<String>> {
+    let dirs = lock_allowed_dirs(state)?;
+    Ok(dirs
+        .iter()
+        .map(|d| d.to_string_lossy().to_string())
+        .collect())
+}
+
+/// Replaces the allowed directories with a subset of the current grants.
+pub fn fs_set_allowed_dirs(state: &State<'_, AppState>, dirs: Vec<String>) -> Result<()> {
+    let mut current = lock_allowed_dirs(state)?;
+    let next = narrow_allowed_dirs(&current, dirs)?;
+    system::set_allowed_dirs_in_store(state, &next)?;
+    *current = next;
+    Ok(())
+}
+
+/// Revokes access to `dir` and to any allowed directory nested inside it.
+pub fn fs_revoke_allowed_dir(state: &State<'_, AppState>, dir: String) -> Result<()> {
+    let dir = parse_allowed_dir(&dir)?;
+    let mut current = lock_allowed_dirs(state)?;
+    let next = revoke_from_allowed_dirs(&current, &dir)?;
+    system::set_allowed_dirs_in_store(state, &next)?;
+    *current = next;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("drome-fs-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn revoked_dir_is_no_longer_allowed() {
+        let base = std::env::temp_dir();
+        let notes = base.join("notes");
+        let other = base.join("other");
+        let current = vec![notes.clone(), notes.join("sub"), other.clone()];
+
+        let next = revoke_from_allowed_dirs(&current, &notes).unwrap();
+
+        assert_eq!(next, vec![other.clone()]);
+        assert!(!is_in_allowed_dirs(&next, &notes.join("a.md")));
+        assert!(!is_in_allowed_dirs(&next, &notes.join("sub").join("b.md")));
+        assert!(is_in_allowed_dirs(&next, &other.join("c.md")));
+    }
+
+    #[test]
+    fn revoking_dir_under_broader_grant_is_rejected() {
+        let notes = std::env::temp_dir().join("notes");
+        let current = vec![notes.clone()];
+
+        assert!(revoke_from_allowed_dirs(&current, &notes.join("private")).is_err());
+    }
+
+    #[test]
+    fn revoking_dir_that_was_never_granted_is_rejected() {
+        let base = std::env::temp_dir();
+        let current = vec![base.join("notes")];
+
+        let err = revoke_from_allowed_dirs(&current, &base.join("other")).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Directory is not currently allowed"));
+        assert!(revoke_from_allowed_dirs(&[], &base.join("notes")).is_err());
+    }
+
+    #[test]
+    fn set_only_narrows_existing_grants() {
+        let base = scratch_dir();
+        let sub = base.join("sub");
+        std::fs::create_dir_all(&sub).unwrap();
+        let current = vec![base.clone()];
+
+        let next = narrow_allowed_dirs(&current, vec![sub.to_string_lossy().to_string()]).unwrap();
+        assert_eq!(next, vec![sub.clone()]);
+
+        let root = base
+            .ancestors()
+            .last()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        assert!(narrow_allowed_dirs(&current, vec![root]).is_err());
+        assert!(narrow_allowed_dirs(&current, vec![String::new()]).is_err());
+        assert!(narrow_allowed_dirs(&current, vec!["relative/dir".into()]).is_err());
+        let missing = base.join("missing").to_string_lossy().to_string();
+        assert!(narrow_allowed_dirs(&current, vec![missing]).is_err());
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -1,7 +1,7 @@
 use base64::Engine;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::io::Read;
+use std::io::{ErrorKind, Read};
 use std::sync::mpsc::{self, Receiver};
 use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
@@ -51,12 +51,149 @@ pub struct HttpFetchStreamReadResponse {
     pub done: bool,
     pub chunk_base64: Option<String>,
     pub error: Option<String>,
+    /// Stable identifier for known failures, e.g. `stream_truncated`.
+    pub error_code: Option<String>,
+    pub retriable: bool,
+}
+
+impl HttpFetchStreamReadResponse {
+    fn pending(chunk_base64: Option<String>) -> Self {
+        Self {
+            done: false,
+            chunk_base64,
+            error: None,
+            error_code: None,
+            retriable: false,
+        }
+    }
+
+    fn finished() -> Self {
+        Self {
+            done: true,
+            ..Self::pending(None)
+        }
+    }
+
+    fn failed(err: StreamError) -> Self {
+        Self {
+            error: Some(err.message),
+            error_code: err.code.map(str::to_string),
+            retriable: err.retriable,
+            ..Self::finished()
+        }
+    }
+}
+
+struct StreamError {
+    message: String,
+    code: Option<&'static str>,
+    retriable: bool,
+}
+
+impl StreamError {
+    fn message(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            code: None,
+            retriable: false,
+        }
+    }
+
+    /// Maps a body read error. A compressed stream that ends early surfaces as
+    /// `UnexpectedEof` and is reported as a retriable `stream_truncated`. Other
+    /// decoder failures (corrupt data) would fail the same way on retry, so they
+    /// get their own non-retriable code.
+    fn from_read(err: &std::io::Error) -> Self {
+        let chain: Vec<&DynError> = error_chain(err).collect();
+        let io_kind = |e: &&DynError| e.downcast_ref::<std::io::Error>().map(|io| io.kind());
+
+        if chain
+            .iter()
+            .filter_map(io_kind)
+            .any(|kind| kind == ErrorKind::UnexpectedEof)
+        {
+            return Self {
+                message: "Response stream ended before it was complete".to_string(),
+                code: Some("stream_truncated"),
+                retriable: true,
+            };
+        }
+
+        // Decoders report failures as `io::Error`s beneath reqwest's body error,
+        // while network failures come through hyper with network error kinds.
+        let below_reqwest = chain
+            .iter()
+            .position(|e| e.downcast_ref::<reqwest::Error>().is_some())
+            .map(|at| &chain[at..]);
+        let is_decode = below_reqwest.is_some_and(|errors| {
+            errors[0]
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(|e| e.is_decode())
+                || errors[1..]
+                    .iter()
+                    .rev()
+                    .find_map(io_kind)
+                    .is_some_and(|kind| matches!(kind, ErrorKind::InvalidData | ErrorKind::Other))
+        });
+        if is_decode {
+            let detail = chain.last().map(|e| e.to_string()).unwrap_or_default();
+            return Self {
+                message: format!("Failed to decode response body: {detail}"),
+                code: Some("stream_decode_failed"),
+                retriable: false,
+            };
+        }
+        Self::message(err.to_string())
+    }
+
+    /// An expected event stream came back as a JSON document, which some
+    /// proxies use to report errors with a 200 status.
+    fn unexpected_json(body: &[u8]) -> Self {
+        let detail = serde_json::from_slice::<serde_json::Value>(body)
+            .ok()
+            .and_then(|v| {
+                v.pointer("/error/message")
+                    .or_else(|| v.get("message"))
+                    .or_else(|| v.get("error"))
+                    .and_then(|m| m.as_str())
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| String::from_utf8_lossy(body).trim().to_string());
+        Self {
+            message: format!("Expected an event stream but received JSON: {detail}"),
+            code: Some("unexpected_json_body"),
+            retriable: false,
+        }
+    }
+}
+
+type DynError = dyn std::error::Error + 'static;
+
+/// Walks `err` and its sources. `io::Error::source` skips the error it wraps,
+/// so wrapped errors are reached through `get_ref` instead.
+fn error_chain(err: &std::io::Error) -> impl Iterator<Item = &DynError> {
+    std::iter::successors(Some(err as &DynError), |current| {
+        match current.downcast_ref::<std::io::Error>() {
+            Some(io) => io.get_ref().map(|inner| inner as &DynError),
+            None => current.source(),
+        }
+    })
+}
+
+fn is_json_content_type(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    media_type == "application/json" || media_type.ends_with("+json")
 }
 
 enum StreamChunk {
     Data(Vec<u8>),
     Done,
-    Error(String),
+    Error(StreamError),
 }
 
 struct StreamSession {
@@ -81,7 +218,13 @@ fn build_request(req: HttpFetchRequest) -> Result<reqwest::blocking::RequestBuil
     let method = reqwest::Method::from_bytes(method.as_bytes())
         .map_err(|e| DromeError::Message(format!("Invalid HTTP method: {e}")))?;
 
-    let mut builder = reqwest::blocking::Client::builder();
+    // Some OpenAI-compatible gateways compress SSE responses. Decoding here
+    // hands the renderer plain bytes (Content-Encoding is dropped from the
+    // forwarded headers), so its stream parser never sees compressed frames.
+    let mut builder = reqwest::blocking::Client::builder()
+        .gzip(true)
+        .brotli(true)
+        .deflate(true);
     if let Some(ms) = req.timeout_ms {
         builder = builder.timeout(Duration::from_millis(ms));
     }
@@ -168,11 +311,25 @@ pub fn http_fetch_stream_start(req: HttpFetchRequest) -> Result<HttpFetchStreamS
     let status = response.status();
     let headers = collect_headers(&response);
     let stream_id = Uuid::new_v4().to_string();
+    // Streams are only started for SSE requests, so a successful JSON response
+    // is a proxy-reported error rather than an event stream.
+    let unexpected_json = status.is_success()
+        && response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(is_json_content_type);
 
     let (tx, rx) = mpsc::sync_channel::<StreamChunk>(32);
 
     std::thread::spawn(move || {
         let mut response = response;
+        if unexpected_json {
+            let mut body = Vec::new();
+            let _ = (&mut response).take(64 * 1024).read_to_end(&mut body);
+            let _ = tx.send(StreamChunk::Error(StreamError::unexpected_json(&body)));
+            return;
+        }
         let mut buffer = [0u8; 8 * 1024];
 
         loop {
@@ -190,7 +347,7 @@ pub fn http_fetch_stream_start(req: HttpFetchRequest) -> Result<HttpFetchStreamS
                     }
                 }
                 Err(err) => {
-                    let _ = tx.send(StreamChunk::Error(err.to_string()));
+                    let _ = tx.send(StreamChunk::Error(StreamError::from_read(&err)));
                     break;
                 }
             }
@@ -220,11 +377,9 @@ pub fn http_fetch_stream_read(
     };
 
     let Some(session) = session else {
-        return Ok(HttpFetchStreamReadResponse {
-            done: true,
-            chunk_base64: None,
-            error: Some("HTTP stream not found".to_string()),
-        });
+        return Ok(HttpFetchStreamReadResponse::failed(StreamError::message(
+            "HTTP stream not found",
+        )));
     };
 
     match session.rx.recv_timeout(Duration::from_millis(timeout_ms)) {
@@ -234,37 +389,17 @@ pub fn http_fetch_stream_read(
             let mut sessions = lock_stream_sessions()?;
             sessions.insert(req.stream_id, session);
 
-            Ok(HttpFetchStreamReadResponse {
-                done: false,
-                chunk_base64: Some(chunk_base64),
-                error: None,
-            })
+            Ok(HttpFetchStreamReadResponse::pending(Some(chunk_base64)))
         }
-        Ok(StreamChunk::Done) => Ok(HttpFetchStreamReadResponse {
-            done: true,
-            chunk_base64: None,
-            error: None,
-        }),
-        Ok(StreamChunk::Error(err)) => Ok(HttpFetchStreamReadResponse {
-            done: true,
-            chunk_base64: None,
-            error: Some(err),
-        }),
+        Ok(StreamChunk::Done) => Ok(HttpFetchStreamReadResponse::finished()),
+        Ok(StreamChunk::Error(err)) => Ok(HttpFetchStreamReadResponse::failed(err)),
         Err(mpsc::RecvTimeoutError::Timeout) => {
             let mut sessions = lock_stream_sessions()?;
             sessions.insert(req.stream_id, session);
 
-            Ok(HttpFetchStreamReadResponse {
-                done: false,
-                chunk_base64: None,
-                error: None,
-            })
+            Ok(HttpFetchStreamReadResponse::pending(None))
         }
-        Err(mpsc::RecvTimeoutError::Disconnected) => Ok(HttpFetchStreamReadResponse {
-            done: true,
-            chunk_base64: None,
-            error: None,
-        }),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Ok(HttpFetchStreamReadResponse::finished()),
     }
 }
 
@@ -274,3 +409,146 @@ pub fn http_fetch_stream_cancel(stream_id: String) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+    use std::net::TcpListener;
+
+    fn gzip(body: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(body).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// Serves a single response with the given headers and closes the connection.
+    fn serve_once(content_type: &str, content_encoding: Option<&str>, body: Vec<u8>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut head = format!("HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\n");
+        if let Some(encoding) = content_encoding {
+            head.push_str(&format!("Content-Encoding: {encoding}\r\n"));
+        }
+        head.push_str("Connection: close\r\n\r\n");
+        std::thread::spawn(move || {
+            let (mut conn, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = conn.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            conn.write_all(head.as_bytes()).unwrap();
+            conn.write_all(&body).unwrap();
+        });
+        format!("http://{addr}/v1/chat/completions")
+    }
+
+    fn serve_gzip_once(body: Vec<u8>) -> String {
+        serve_once("text/event-stream", Some("gzip"), body)
+    }
+
+    fn read_stream(url: String) -> (Vec<u8>, HttpFetchStreamReadResponse) {
+        let start = http_fetch_stream_start(HttpFetchRequest {
+            url,
+            method: None,
+            headers: None,
+            body: None,
+            body_base64: None,
+            timeout_ms: Some(5_000),
+        })
+        .unwrap();
+        assert!(start.ok);
+        assert!(!start.headers.contains_key("content-encoding"));
+
+        let mut bytes = Vec::new();
+        loop {
+            let read = http_fetch_stream_read(HttpFetchStreamReadRequest {
+                stream_id: start.stream_id.clone(),
+                timeout_ms: Some(5_000),
+            })
+            .unwrap();
+            if let Some(chunk) = &read.chunk_base64 {
+                bytes.extend(
+                    base64::engine::general_purpose::STANDARD
+                        .decode(chunk)
+                        .unwrap(),
+                );
+            }
+            if read.done {
+                return (bytes, read);
+            }
+        }
+    }
+
+    fn sse_payload() -> Vec<u8> {
+        (0..500)
+            .map(|i| format!("data: {{\"index\":{i}}}\n\n"))
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    #[test]
+    fn gzip_stream_is_decoded() {
+        let payload = sse_payload();
+        let (bytes, last) = read_stream(serve_gzip_once(gzip(&payload)));
+
+        assert_eq!(bytes, payload);
+        assert!(last.error.is_none());
+    }
+
+    #[test]
+    fn truncated_gzip_stream_reports_stream_truncated() {
+        let compressed = gzip(&sse_payload());
+        let truncated = compressed[..compressed.len() / 2].to_vec();
+        let (_, last) = read_stream(serve_gzip_once(truncated));
+
+        assert_eq!(last.error_code.as_deref(), Some("stream_truncated"));
+        assert!(last.retriable);
+    }
+
+    #[test]
+    fn unrelated_read_errors_keep_their_message() {
+        let err = StreamError::from_read(&std::io::Error::other("connection reset"));
+        assert_eq!(err.code, None);
+        assert!(!err.retriable);
+        assert_eq!(err.message, "connection reset");
+
+        let wrapped =
+            std::io::Error::other(std::io::Error::from(std::io::ErrorKind::UnexpectedEof));
+        assert_eq!(
+            StreamError::from_read(&wrapped).code,
+            Some("stream_truncated")
+        );
+    }
+
+    #[test]
+    fn corrupt_gzip_stream_is_not_retriable() {
+        let mut corrupt = gzip(&sse_payload());
+        let len = corrupt.len();
+        for byte in &mut corrupt[20..len - 8] {
+            *byte = 0xff;
+        }
+        let (_, last) = read_stream(serve_gzip_once(corrupt));
+
+        assert_eq!(last.error_code.as_deref(), Some("stream_decode_failed"));
+        assert!(!last.retriable);
+    }
+
+    #[test]
+    fn json_body_on_event_stream_is_a_structured_failure() {
+        let body = br#"{"error":{"message":"quota exceeded","type":"insufficient_quota"}}"#;
+        let url = serve_once("application/json; charset=utf-8", None, body.to_vec());
+        let (bytes, last) = read_stream(url);
+
+        assert!(bytes.is_empty());
+        assert_eq!(last.error_code.as_deref(), Some("unexpected_json_body"));
+        assert!(!last.retriable);
+        assert!(last.error.unwrap().ends_with("quota exceeded"));
+    }
+}
@@ -49,7 +49,11 @@ pub async fn ipc_invoke(
             "open:website" => {
                 to_value(commands::app::open_website(&app, arg::<String>(&args, 0)?)?)
             }
-            "open:path" => to_value(commands::app::open_path(&app, arg::<String>(&args, 0)?)?),
+            "open:path" => to_value(commands::app::open_path(
+                &app,
+                &state,
+                arg::<String>(&args, 0)?,
+            )?),
             "app:log-to-main" => to_value(commands::app::app_log_to_main(args)?),
             "app:get-disk-info" => {
                 to_value(commands::app::app_get_disk_info(arg::<String>(&args, 0)?)?)
@@ -188,6 +192,7 @@ pub async fn ipc_invoke(
             )?),
             "file:openPath" => to_value(commands::file::file_open_path(
                 &app,
+                &state,
                 arg::<String>(&args, 0)?,
             )?),
             "file:select" => to_value(commands::file::file_select(
@@ -281,6 +286,11 @@ pub async fn ipc_invoke(
                 &state,
                 arg::<String>(&args, 0)?,
             )?),
+            "file:thumbnail" => to_value(commands::file::file_thumbnail(
+                &state,
+                arg::<String>(&args, 0)?,
+                opt_arg::<u32>(&args, 1)?.unwrap_or(256),
+            )?),
             "file:saveBase64Image" => to_value(commands::file::file_save_base64_image(
                 &state,
                 arg::<String>(&args, 0)?,
@@ -293,8 +303,9 @@ pub async fn ipc_invoke(
             "file:download" => {
                 let url = arg::<String>(&args, 0)?;
                 let is_use_content_type = opt_arg::<bool>(&args, 1)?;
+                let options = opt_arg::<Value>(&args, 2)?;
                 to_value(tokio::task::block_in_place(|| {
-                    commands::file::file_download(&state, url, is_use_content_type)
+                    commands::file::file_download(&state, url, is_use_content_type, options)
                 })?)
             }
             "file:copy" => to_value(commands::file::file_copy(
@@ -331,6 +342,29 @@ pub async fn ipc_invoke(
                 arg::<String>(&args, 0)?,
                 args.get(1).cloned(),
             )?),
+            "file:indexDirectory" => to_value(commands::file::file_index_directory(
+                &app,
+                &window,
+                &state,
+                arg::<String>(&args, 0)?,
+                args.get(1).cloned(),
+            )?),
+            "file:cancelIndex" => {
+                to_value(commands::file::file_cancel_index(arg::<String>(&args, 0)?)?)
+            }
+            "notes:create" => to_value(commands::file::notes_create(
+                &state,
+                arg::<String>(&args, 0)?,
+                arg::<String>(&args, 1)?,
+                arg::<String>(&args, 2)?,
+                arg::<bool>(&args, 3)?,
+            )?),
+            "notes:move" => to_value(commands::file::notes_move(
+                &state,
+                arg::<String>(&args, 0)?,
+                arg::<String>(&args, 1)?,
+                arg::<String>(&args, 2)?,
+            )?),
             "file:getDirectoryStructure" => to_value(commands::file::file_get_directory_structure(
                 &state,
                 arg::<String>(&args, 0)?,
@@ -361,6 +395,7 @@ pub async fn ipc_invoke(
             )?),
             "file:showInFolder" => to_value(commands::file::file_show_in_folder(
                 &app,
+                &state,
                 arg::<String>(&args, 0)?,
             )?),
 
@@ -376,6 +411,15 @@ pub async fn ipc_invoke(
                 &state,
                 arg::<String>(&args, 0)?,
             )?),
+            "fs:listAllowedDirs" => to_value(commands::fs::fs_list_allowed_dirs(&state)?),
+            "fs:setAllowedDirs" => to_value(commands::fs::fs_set_allowed_dirs(
+                &state,
+                arg::<Vec<String>>(&args, 0)?,
+            )?),
+            "fs:revokeAllowedDir" => to_value(commands::fs::fs_revoke_allowed_dir(
+                &state,
+                arg::<String>(&args, 0)?,
+            )?),
             "app:resolve-path" => to_value(commands::file::resolve_path(arg::<String>(&args, 0)?)?),
             "app:is-path-inside" => to_value(commands::file::is_path_inside(
                 arg::<String>(&args, 0)?,
@@ -738,6 +782,21 @@ pub async fn ipc_invoke(
                         .block_on(commands::mcp::mcp_get_server_logs(server))
                 })?)
             }
+            "mcp:set-log-capture" => {
+                let server = arg::<commands::mcp::McpServer>(&args, 0)?;
+                let enabled = arg::<bool>(&args, 1)?;
+                to_value(tokio::task::block_in_place(|| {
+                    tokio::runtime::Handle::current()
+                        .block_on(commands::mcp::mcp_set_log_capture(server, enabled))
+                })?)
+            }
+            "mcp:clear-server-logs" => {
+                let server = arg::<commands::mcp::McpServer>(&args, 0)?;
+                to_value(tokio::task::block_in_place(|| {
+                    tokio::runtime::Handle::current()
+                        .block_on(commands::mcp::mcp_clear_server_logs(server))
+                })?)
+            }
 
             // Misc stubs for optional integrations
             c if c.starts_with("mcp:") => Ok(Value::Null),
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, OnceLock, Weak};
 use std::time::Duration;
 
@@ -21,7 +22,7 @@ use rmcp::{ClientHandler, ServiceError, ServiceExt};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 use tauri::{AppHandle, Emitter};
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader, Lines};
 use tokio::sync::{Mutex, Notify};
 use uuid::Uuid;
 
@@ -330,6 +331,7 @@ struct ManagedClient {
 struct McpState {
     clients: HashMap<String, ManagedClient>,
     server_logs: HashMap<String, VecDeque<McpServerLogEntry>>,
+    log_capture: HashMap<String, Arc<AtomicBool>>,
     active_calls: HashMap<String, ActiveToolCall>,
     progress_to_call: HashMap<String, String>,
 }
@@ -558,7 +560,11 @@ pub async fn mcp_remove_server(app: &AppHandle, server: McpServer) -> Result<()>
             },
         )
         .await;
-    manager.close_server(&server).await
+    manager.close_server(&server).await?;
+    // Stopping keeps the capture setting for the next connect; removal drops it.
+    let mut state = manager.state.lock().await;
+    state.log_capture.remove(&server_key(&server));
+    Ok(())
 }
 
 pub async fn mcp_stop_server(app: &AppHandle, server: McpServer) -> Result<()> {
@@ -639,6 +645,22 @@ pub async fn mcp_get_server_logs(server: McpServer) -> Result<Vec<McpServerLogEn
         .unwrap_or_default())
 }
 
+pub async fn mcp_set_log_capture(server: McpServer, enabled: bool) -> Result<()> {
+    let manager = manager();
+    let key = server_key(&server);
+    let capture = manager.log_capture_flag(&key).await;
+    capture.store(enabled, Ordering::Relaxed);
+    Ok(())
+}
+
+pub async fn mcp_clear_server_logs(server: McpServer) -> Result<()> {
+    let manager = manager();
+    let key = server_key(&server);
+    let mut state = manager.state.lock().await;
+    state.server_logs.remove(&key);
+    Ok(())
+}
+
 pub async fn mcp_list_tools(app: &AppHandle, server: McpServer) -> Result<Vec<McpTool>> {
     let manager = manager();
     let key = server_key(&server);
@@ -902,7 +924,37 @@ pub async fn mcp_abort_tool(call_id: String) -> Result<bool> {
     Ok(true)
 }
 
+/// Reads stderr until the next non-empty line that should be logged. Lines are
+/// still drained while capture is off so the child never blocks on a full
+/// pipe; they are just dropped.
+async fn next_captured_stderr_line<R: AsyncRead + Unpin>(
+    lines: &mut Lines<BufReader<R>>,
+    capture: &AtomicBool,
+) -> std::io::Result<Option<String>> {
+    while let Some(line) = lines.next_line().await? {
+        if !capture.load(Ordering::Relaxed) {
+            continue;
+        }
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            return Ok(Some(trimmed.to_string()));
+        }
+    }
+    Ok(None)
+}
+
 impl McpManager {
+    /// Stderr capture flag for a server; shared with its reader task so the
+    /// setting survives reconnects and can be flipped without locking state.
+    async fn log_capture_flag(&self, server_key: &str) -> Arc<AtomicBool> {
+        let mut state = self.state.lock().await;
+        state
+            .log_capture
+            .entry(server_key.to_string())
+            .or_insert_with(|| Arc::new(AtomicBool::new(true)))
+            .clone()
+    }
+
     async fn append_server_log(
         &self,
         app: &AppHandle,
@@ -975,7 +1027,8 @@ impl McpManager {
         };
 
         if let Some(stderr) = stderr_stream {
-            self.spawn_stderr_reader(app.clone(), server.id.clone(), key.clone(), stderr);
+            let capture = self.log_capture_flag(&key).await;
+            self.spawn_stderr_reader(app.clone(), server.id.clone(), key.clone(), capture, stderr);
         }
 
         self.append_server_log(
@@ -1012,18 +1065,15 @@ impl McpManager {
         app: AppHandle,
         server_id: String,
         server_key: String,
+        capture: Arc<AtomicBool>,
         stderr: tokio::process::ChildStderr,
     ) {
         let manager = Arc::downgrade(self);
         tokio::spawn(async move {
             let mut lines = BufReader::new(stderr).lines();
             loop {
-                match lines.next_line().await {
+                match next_captured_stderr_line(&mut lines, &capture).await {
                     Ok(Some(line)) => {
-                        let trimmed = line.trim();
-                        if trimmed.is_empty() {
-                            continue;
-                        }
                         let Some(manager) = manager.upgrade() else {
                             break;
                         };
@@ -1035,7 +1085,7 @@ impl McpManager {
                                 McpServerLogEntry {
                                     timestamp: now_ms(),
                                     level: McpServerLogLevel::Stderr,
-                                    message: trimmed.to_string(),
+                                    message: line,
                                     data: None,
                                     source: Some("stdio".to_string()),
                                 },
@@ -1671,3 +1721,45 @@ fn build_http_transport(
 
     Ok(StreamableHttpClientTransport::with_client(client, config))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncWriteExt;
+
+    #[tokio::test]
+    async fn stderr_lines_are_dropped_while_capture_is_off() {
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        let mut lines = BufReader::new(reader).lines();
+        let capture = AtomicBool::new(true);
+        let mut logs = Vec::new();
+
+        writer.write_all(b"starting\n\n").await.unwrap();
+        let line = next_captured_stderr_line(&mut lines, &capture).await;
+        logs.extend(line.unwrap());
+
+        capture.store(false, Ordering::Relaxed);
+        writer.write_all(b"noisy 1\nnoisy 2\n").await.unwrap();
+        let pending = tokio::time::timeout(
+            Duration::from_millis(50),
+            next_captured_stderr_line(&mut lines, &capture),
+        )
+        .await;
+        assert!(
+            pending.is_err(),
+            "no line should be captured while disabled"
+        );
+
+        capture.store(true, Ordering::Relaxed);
+        writer.write_all(b"resumed\n").await.unwrap();
+        drop(writer);
+        while let Some(line) = next_captured_stderr_line(&mut lines, &capture)
+            .await
+            .unwrap()
+        {
+            logs.push(line);
+        }
+
+        assert_eq!(logs, vec!["starting", "resumed"]);
+    }
+}
@@ -307,3 +307,18 @@ pub fn add_allowed_dir_to_store(state: &State<'_, AppState>, dir: &Path) -> Resu
     write_store(&store, &map)?;
     Ok(())
 }
+
+pub fn set_allowed_dirs_in_store(state: &State<'_, AppState>, dirs: &[PathBuf]) -> Result<()> {
+    let store = store_path(state);
+    let mut map = read_store(&store)?;
+    map.insert(
+        "allowedDirs".into(),
+        Value::Array(
+            dirs.iter()
+                .map(|d| Value::String(d.to_string_lossy().to_string()))
+                .collect(),
+        ),
+    );
+    write_store(&store, &map)?;
+    Ok(())
+}
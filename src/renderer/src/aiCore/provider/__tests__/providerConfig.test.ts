@@ -76,13 +76,16 @@ vi.mock('@renderer/services/AssistantService', () => ({
   })
 }))
 
+import { parseJsonEventStream } from '@ai-sdk/provider-utils'
 import { getProviderByModel } from '@renderer/services/AssistantService'
 import type { Model, Provider } from '@renderer/types'
 import { formatApiHost } from '@renderer/utils/api'
 import { isAzureOpenAIProvider, isCherryAIProvider, isPerplexityProvider } from '@renderer/utils/provider'
+import { Base64 } from 'js-base64'
+import * as z from 'zod'
 
 import { COPILOT_DEFAULT_HEADERS, COPILOT_EDITOR_VERSION, isCopilotResponsesModel } from '../constants'
-import { getActualProvider, providerToAiSdkConfig } from '../providerConfig'
+import { getActualProvider, nativeStreamFetch, providerToAiSdkConfig } from '../providerConfig'
 
 const { __mockGetState: mockGetState } = vi.mocked(await import('@renderer/store')) as any
 
@@ -558,3 +561,74 @@ describe('Azure OpenAI traditional API routing', () => {
     expect(previewConfig.options.useDeploymentBasedUrls).toBeUndefined()
   })
 })
+
+describe('Native stream fetch', () => {
+  const request = {
+    url: 'https://gateway.example.com/v1/chat/completions',
+    method: 'POST',
+    headers: { accept: 'text/event-stream' },
+    body: '{"stream":true}'
+  }
+
+  // Serves `chunks` from http:fetch-stream:read one call at a time, then `final`.
+  const createInvoke = (chunks: string[], final: Record<string, unknown> = { done: true }) => {
+    const pending = [...chunks]
+    return vi.fn(async (channel: string) => {
+      if (channel === 'http:fetch-stream:start') {
+        return { streamId: 'stream-1', ok: true, status: 200, headers: { 'content-type': 'text/event-stream' } }
+      }
+      if (channel === 'http:fetch-stream:read') {
+        const chunk = pending.shift()
+        return chunk === undefined
+          ? final
+          : { done: false, chunkBase64: Base64.fromUint8Array(new TextEncoder().encode(chunk)) }
+      }
+      return undefined
+    })
+  }
+
+  const readIndexes = async (response: Response) => {
+    const events = parseJsonEventStream({ stream: response.body!, schema: z.object({ index: z.number() }) })
+    const reader = events.getReader()
+    const indexes: number[] = []
+    for (;;) {
+      const { done, value } = await reader.read()
+      if (done) return indexes
+      if (!value.success) throw value.error
+      indexes.push(value.value.index)
+    }
+  }
+
+  it('parses three events delivered in a single chunk', async () => {
+    const invoke = createInvoke(['data: {"index":0}\n\ndata: {"index":1}\n\ndata: {"index":2}\n\n'])
+
+    const response = await nativeStreamFetch(invoke, request)
+
+    expect(await readIndexes(response)).toEqual([0, 1, 2])
+  })
+
+  it('parses one event split across five chunks', async () => {
+    const invoke = createInvoke(['da', 'ta: {"in', 'dex"', ':7}\n', '\n'])
+
+    const response = await nativeStreamFetch(invoke, request)
+
+    expect(await readIndexes(response)).toEqual([7])
+  })
+
+  it('surfaces the structured failure code from the stream reader', async () => {
+    const invoke = createInvoke([], {
+      done: true,
+      error: 'Expected an event stream but received JSON: quota exceeded',
+      errorCode: 'unexpected_json_body',
+      retriable: false
+    })
+
+    const response = await nativeStreamFetch(invoke, request)
+
+    await expect(response.text()).rejects.toMatchObject({
+      message: 'Expected an event stream but received JSON: quota exceeded',
+      code: 'unexpected_json_body',
+      retriable: false
+    })
+  })
+})
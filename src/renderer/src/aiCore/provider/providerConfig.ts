@@ -71,6 +71,8 @@ type IpcHttpFetchStreamReadResponse = {
   done: boolean
   chunkBase64?: string | null
   error?: string | null
+  errorCode?: string | null
+  retriable?: boolean
 }
 
 function headersToRecord(init?: HeadersInit): Record<string, string> {
@@ -147,7 +149,7 @@ function isStreamingRequest(req: IpcHttpFetchRequest): boolean {
   }
 }
 
-async function nativeStreamFetch(
+export async function nativeStreamFetch(
   invokeChannel: (channel: string, payload: any) => Promise<any>,
   req: IpcHttpFetchRequest
 ): Promise<Response> {
@@ -182,7 +184,12 @@ async function nativeStreamFetch(
       }
 
       if (readResult.error) {
-        controller.error(new TypeError(readResult.error))
+        controller.error(
+          Object.assign(new TypeError(readResult.error), {
+            code: readResult.errorCode ?? undefined,
+            retriable: readResult.retriable ?? false
+          })
+        )
         void invokeChannel('http:fetch-stream:cancel', streamId).catch(() => {})
         return
       }
@@ -167,11 +167,15 @@ export function createWindowApi(ipcRenderer: { invoke: IpcInvoke }): WindowApi {
         return result
       },
       base64Image: (fileId: string) => invoke(IpcChannel.File_Base64Image, fileId),
+      thumbnail: (fileId: string, maxDim?: number) => invoke(IpcChannel.File_Thumbnail, fileId, maxDim),
       saveBase64Image: (data: string) => invoke(IpcChannel.File_SaveBase64Image, data),
       savePastedImage: (imageData: Uint8Array, extension?: string) =>
         invoke(IpcChannel.File_SavePastedImage, imageData, extension),
-      download: (url: string, isUseContentType?: boolean) =>
-        invoke(IpcChannel.File_Download, url, isUseContentType),
+      download: (
+        url: string,
+        isUseContentType?: boolean,
+        options?: { maxBytes?: number; acceptContentTypes?: string[] }
+      ) => invoke(IpcChannel.File_Download, url, isUseContentType, options),
       copy: (fileId: string, destPath: string) => invoke(IpcChannel.File_Copy, fileId, destPath),
       base64File: (fileId: string) => invoke(IpcChannel.File_Base64File, fileId),
       pdfInfo: (fileId: string) => invoke(IpcChannel.File_GetPdfInfo, fileId),
@@ -181,6 +185,20 @@ export function createWindowApi(ipcRenderer: { invoke: IpcInvoke }): WindowApi {
       isDirectory: (filePath: string) => invoke(IpcChannel.File_IsDirectory, filePath),
       getDirectoryStructure: (dirPath: string) => invoke(IpcChannel.File_GetDirectoryStructure, dirPath),
       listDirectory: (dirPath: string, options?: any) => invoke(IpcChannel.File_ListDirectory, dirPath, options),
+      indexDirectory: (dirPath: string, options?: any) => invoke(IpcChannel.File_IndexDirectory, dirPath, options),
+      cancelIndex: (token: string) => invoke(IpcChannel.File_CancelIndex, token),
+      onIndexEntries: (callback: (data: any) => void) => {
+        const remove = window.electron.ipcRenderer.on('dir:entry', (_: any, payload: any) => {
+          if (payload && typeof payload === 'object') callback(payload)
+        })
+        return () => remove?.()
+      },
+      onIndexDone: (callback: (data: any) => void) => {
+        const remove = window.electron.ipcRenderer.on('dir:done', (_: any, payload: any) => {
+          if (payload && typeof payload === 'object') callback(payload)
+        })
+        return () => remove?.()
+      },
       checkFileName: (dirPath: string, fileName: string, isFile: boolean) =>
         invoke(IpcChannel.File_CheckFileName, dirPath, fileName, isFile),
       validateNotesDirectory: (dirPath: string) => invoke(IpcChannel.File_ValidateNotesDirectory, dirPath),
@@ -207,6 +225,9 @@ export function createWindowApi(ipcRenderer: { invoke: IpcInvoke }): WindowApi {
         return new Uint8Array()
       },
       readText: (pathOrUrl: string) => invoke(IpcChannel.Fs_ReadText, pathOrUrl),
+      listAllowedDirs: () => invoke(IpcChannel.Fs_ListAllowedDirs),
+      setAllowedDirs: (dirs: string[]) => invoke(IpcChannel.Fs_SetAllowedDirs, dirs),
+      revokeAllowedDir: (dir: string) => invoke(IpcChannel.Fs_RevokeAllowedDir, dir),
     },
     export: {
       toWord: async (markdown: string, fileName: string) => {
@@ -217,6 +238,12 @@ export function createWindowApi(ipcRenderer: { invoke: IpcInvoke }): WindowApi {
         })
       },
     },
+    notes: {
+      create: (dirBase: string, treePath: string, name: string, isFolder: boolean) =>
+        invoke(IpcChannel.Notes_Create, dirBase, treePath, name, isFolder),
+      move: (dirBase: string, fromTreePath: string, toTreePath: string) =>
+        invoke(IpcChannel.Notes_Move, dirBase, fromTreePath, toTreePath),
+    },
     obsidian: {
       getVaults: () => safeInvoke(IpcChannel.Obsidian_GetVaults, [] as any),
       getFolders: (vaultName: string) => safeInvoke(IpcChannel.Obsidian_GetFiles, [] as any, vaultName),
@@ -314,6 +341,9 @@ export function createWindowApi(ipcRenderer: { invoke: IpcInvoke }): WindowApi {
       abortTool: (callId: string) => safeInvoke(IpcChannel.Mcp_AbortTool, undefined as any, callId),
       getServerVersion: (server: any) => safeInvoke(IpcChannel.Mcp_GetServerVersion, null as any, server),
       getServerLogs: (server: any) => safeInvoke(IpcChannel.Mcp_GetServerLogs, [] as any, server),
+      setLogCapture: (server: any, enabled: boolean) =>
+        safeInvoke(IpcChannel.Mcp_SetLogCapture, undefined as any, server, enabled),
+      clearServerLogs: (server: any) => safeInvoke(IpcChannel.Mcp_ClearServerLogs, undefined as any, server),
       onServerLog: (callback: (log: any) => void) => {
         const remove = window.electron.ipcRenderer.on(IpcChannel.Mcp_ServerLog, (_: any, payload: any) => callback(payload))
         return () => remove?.()
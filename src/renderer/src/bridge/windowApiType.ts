@@ -53,6 +53,7 @@ import type {
   UninstallPluginPackageResult,
   WritePluginContentOptions
 } from '@types/plugin'
+import type { NotesTreeNode } from '@types/note'
 import type { Notification } from '@types/notification'
 
 export type OpenClawGatewayStatus = 'stopped' | 'starting' | 'running' | 'error'
@@ -189,9 +190,15 @@ export type WindowApi = {
     saveImage: (name: string, data: string) => Promise<FileMetadata>
     binaryImage: (fileId: string) => Promise<{ data: Uint8Array; mime: string }>
     base64Image: (fileId: string) => Promise<{ mime: string; base64: string; data: string }>
+    /** `maxDim` defaults to 256 and must be within 16..=4096. */
+    thumbnail: (fileId: string, maxDim?: number) => Promise<{ mime: string; base64: string; data: string }>
     saveBase64Image: (data: string) => Promise<FileMetadata>
     savePastedImage: (imageData: Uint8Array, extension?: string) => Promise<FileMetadata>
-    download: (url: string, isUseContentType?: boolean) => Promise<any>
+    download: (
+      url: string,
+      isUseContentType?: boolean,
+      options?: { maxBytes?: number; acceptContentTypes?: string[] }
+    ) => Promise<any>
     copy: (fileId: string, destPath: string) => Promise<void>
     base64File: (fileId: string) => Promise<{ data: string; mime: string }>
     pdfInfo: (fileId: string) => Promise<number>
@@ -201,6 +208,15 @@ export type WindowApi = {
     isDirectory: (filePath: string) => Promise<boolean>
     getDirectoryStructure: (dirPath: string) => Promise<any>
     listDirectory: (dirPath: string, options?: any) => Promise<string[]>
+    indexDirectory: (dirPath: string, options?: any) => Promise<string>
+    cancelIndex: (token: string) => Promise<boolean>
+    onIndexEntries: (
+      callback: (data: {
+        token: string
+        entries: { path: string; name: string; isDirectory: boolean; depth: number }[]
+      }) => void
+    ) => () => void
+    onIndexDone: (callback: (data: { token: string; total: number; cancelled: boolean }) => void) => () => void
     checkFileName: (dirPath: string, fileName: string, isFile: boolean) => Promise<{ safeName: string; exists: boolean }>
     validateNotesDirectory: (dirPath: string) => Promise<any>
     startFileWatcher: (dirPath: string, config?: any) => Promise<any>
@@ -215,12 +231,21 @@ export type WindowApi = {
   fs: {
     read: (pathOrUrl: string, encoding?: string) => Promise<string | Uint8Array>
     readText: (pathOrUrl: string) => Promise<string>
+    listAllowedDirs: () => Promise<string[]>
+    /** Narrows access to a subset of the current grants; new dirs must come from a native dialog. */
+    setAllowedDirs: (dirs: string[]) => Promise<void>
+    revokeAllowedDir: (dir: string) => Promise<void>
   }
 
   export: {
     toWord: (markdown: string, fileName: string) => Promise<any>
   }
 
+  notes: {
+    create: (dirBase: string, treePath: string, name: string, isFolder: boolean) => Promise<NotesTreeNode>
+    move: (dirBase: string, fromTreePath: string, toTreePath: string) => Promise<NotesTreeNode>
+  }
+
   obsidian: {
     getVaults: () => Promise<Array<{ path: string; name: string }>>
     getFolders: (vaultName: string) => Promise<Array<{ path: string; type: 'folder' | 'markdown'; name: string }>>
@@ -325,6 +350,8 @@ export type WindowApi = {
     abortTool: (callId: string) => Promise<any>
     getServerVersion: (server: MCPServer) => Promise<string | null>
     getServerLogs: (server: MCPServer) => Promise<MCPServerLogEntry[]>
+    setLogCapture: (server: MCPServer, enabled: boolean) => Promise<void>
+    clearServerLogs: (server: MCPServer) => Promise<void>
     onServerLog: (callback: (log: MCPServerLogEntry & { serverId?: string }) => void) => () => void
   }
 